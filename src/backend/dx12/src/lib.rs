@@ -928,6 +928,9 @@ impl hal::Instance<Backend> for Instance {
                 }
             };
 
+            let conservative_rasterization_supported = features.ConservativeRasterizationTier
+                != d3d12::D3D12_CONSERVATIVE_RASTERIZATION_TIER_NOT_SUPPORTED;
+
             let heterogeneous_resource_heaps =
                 features.ResourceHeapTier != d3d12::D3D12_RESOURCE_HEAP_TIER_1;
 
@@ -1094,6 +1097,7 @@ impl hal::Instance<Backend> for Instance {
                     Features::TESSELLATION_SHADER |
                     Features::NON_FILL_POLYGON_MODE |
                     if depth_bounds_test_supported { Features::DEPTH_BOUNDS } else { Features::empty() } |
+                    if conservative_rasterization_supported { Features::CONSERVATIVE_RASTERIZATION } else { Features::empty() } |
                     //logic_op: false, // Optional on feature level 11_0
                     Features::MULTI_DRAW_INDIRECT |
                     Features::FORMAT_BC |
@@ -624,6 +624,12 @@ impl PhysicalDevice {
     pub fn legacy_features(&self) -> &info::LegacyFeatures {
         &self.0.legacy_features
     }
+
+    /// Get the GL implementation information, including the extensions
+    /// queried once at startup.
+    pub fn info(&self) -> &Info {
+        &self.0.info
+    }
 }
 
 impl adapter::PhysicalDevice<Backend> for PhysicalDevice {
@@ -1347,6 +1347,7 @@ impl adapter::PhysicalDevice<Backend> for PhysicalDevice {
             max_uniform_buffer_range: limits.max_uniform_buffer_range as _,
             min_memory_map_alignment: limits.min_memory_map_alignment,
             standard_sample_locations: limits.standard_sample_locations == ash::vk::TRUE,
+            timestamp_period: limits.timestamp_period,
 
             // TODO: Implement Limits for Mesh Shaders
             //       Depends on VkPhysicalDeviceMeshShaderPropertiesNV which depends on VkPhysicalProperties2
@@ -466,7 +466,7 @@ pub trait CommandBuffer<B: Backend>: fmt::Debug + Any + Send + Sync {
     /// Each draw command in the buffer is a series of 4 `u32` values specifying,
     /// in order, the number of vertices to draw, the number of instances to draw,
     /// the index of the first vertex to draw, and the instance ID of the first
-    /// instance to draw.
+    /// instance to draw, matching the layout of [`DrawIndirectCommand`].
     unsafe fn draw_indirect(
         &mut self,
         buffer: &B::Buffer,
@@ -481,7 +481,8 @@ pub trait CommandBuffer<B: Backend>: fmt::Debug + Any + Send + Sync {
     /// Each draw command in the buffer is a series of 5 values specifying,
     /// in order, the number of indices, the number of instances, the first index,
     /// the vertex offset, and the first instance.  All are `u32`'s except
-    /// the vertex offset, which is an `i32`.
+    /// the vertex offset, which is an `i32`. This matches the layout of
+    /// [`DrawIndexedIndirectCommand`].
     unsafe fn draw_indexed_indirect(
         &mut self,
         buffer: &B::Buffer,
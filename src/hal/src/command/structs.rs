@@ -1,4 +1,4 @@
-use crate::{buffer, image};
+use crate::{buffer, image, IndexCount, InstanceCount, VertexCount, VertexOffset};
 
 use std::ops::Range;
 
@@ -84,3 +84,37 @@ pub struct ImageBlit {
     /// Destination image bounds.
     pub dst_bounds: Range<image::Offset>,
 }
+
+/// Parameters of a single draw read from an indirect buffer
+/// by `draw_indirect()` and `draw_indirect_count()`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DrawIndirectCommand {
+    /// Number of vertices to draw.
+    pub vertex_count: VertexCount,
+    /// Number of instances to draw.
+    pub instance_count: InstanceCount,
+    /// Index of the first vertex.
+    pub first_vertex: VertexCount,
+    /// Instance ID of the first instance.
+    pub first_instance: InstanceCount,
+}
+
+/// Parameters of a single indexed draw read from an indirect buffer
+/// by `draw_indexed_indirect()` and `draw_indexed_indirect_count()`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DrawIndexedIndirectCommand {
+    /// Number of indices to draw.
+    pub index_count: IndexCount,
+    /// Number of instances to draw.
+    pub instance_count: InstanceCount,
+    /// Index of the first index.
+    pub first_index: IndexCount,
+    /// Value added to each index before fetching the vertex.
+    pub vertex_offset: VertexOffset,
+    /// Instance ID of the first instance.
+    pub first_instance: InstanceCount,
+}
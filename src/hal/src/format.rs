@@ -366,6 +366,15 @@ macro_rules! formats {
             $(BaseFormat(SurfaceType::$surface_tail, ChannelType::$channel_tail), )*
         ];
 
+        /// All the defined formats, excluding `Undefined`, in declaration order.
+        ///
+        /// Can be used together with [`format_properties`][crate::adapter::PhysicalDevice::format_properties]
+        /// to find out which formats are supported by a physical device.
+        pub const ALL_FORMATS: [Format; NUM_FORMATS-1] = [
+              Format::$name,
+            $(Format::$name_tail, )*
+        ];
+
             /// A struct equivalent to the matching `Format` enum member, which allows
             /// an API to be strongly typed on particular formats.
             #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
@@ -263,6 +263,8 @@ bitflags! {
         const TASK_SHADER = 0x0001 << 96;
         /// Supports mesh shader stage.
         const MESH_SHADER = 0x0002 << 96;
+        /// Supports conservative rasterization.
+        const CONSERVATIVE_RASTERIZATION = 0x0004 << 96;
     }
 }
 
@@ -415,6 +417,10 @@ pub struct Limits {
     pub max_color_attachments: usize,
     ///
     pub standard_sample_locations: bool,
+    /// The number of nanoseconds it takes for a timestamp query value to be incremented by 1.
+    ///
+    /// Zero if the backend doesn't report it.
+    pub timestamp_period: f32,
     /// The alignment of the start of the buffer used as a GPU copy source, in bytes, non-zero.
     pub optimal_buffer_copy_offset_alignment: buffer::Offset,
     /// The alignment of the row pitch of the texture data stored in a buffer that is
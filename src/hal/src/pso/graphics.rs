@@ -226,6 +226,8 @@ pub struct Rasterizer {
     /// What depth bias, if any, to use for the drawn primitives.
     pub depth_bias: Option<State<DepthBias>>,
     /// Controls how triangles will be rasterized depending on their overlap with pixels.
+    ///
+    /// Can be `true` only if `Features::CONSERVATIVE_RASTERIZATION` is enabled.
     pub conservative: bool,
     /// Controls width of rasterized line segments.
     pub line_width: State<f32>,
@@ -242,6 +244,14 @@ impl Rasterizer {
         conservative: false,
         line_width: State::Static(1.0),
     };
+
+    /// Wireframe rasterizer state, drawing polygon edges as lines.
+    ///
+    /// Requires `Features::NON_FILL_POLYGON_MODE`.
+    pub const LINE: Self = Rasterizer {
+        polygon_mode: PolygonMode::Line,
+        ..Rasterizer::FILL
+    };
 }
 
 /// A description of an equation for how to blend transparent, overlapping fragments.